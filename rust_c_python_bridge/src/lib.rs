@@ -6,9 +6,16 @@
  * (pickling) or file-based communication.
  */
 
+use std::cell::RefCell;
+use std::collections::HashMap;
 use std::ffi::CStr;
+use std::io::{Read, Write};
+use std::net::{TcpStream, ToSocketAddrs};
 use std::os::raw::{c_char, c_int, c_double, c_void};
 use std::slice;
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+use std::time::Instant;
 
 /// Research data structure for QXR system
 #[derive(Debug, Clone)]
@@ -39,11 +46,772 @@ pub struct QXRSocialPost {
     pub engagement_score: f64,
 }
 
+/// A resolved field value pulled from `QXRResearchData` (or a derived value like
+/// `performance_score`) for substitution into a rendered template.
+#[derive(Debug, Clone)]
+enum FieldValue {
+    Int(i64),
+    Float(f64),
+    Str(String),
+}
+
+impl FieldValue {
+    fn format(&self, precision: Option<usize>) -> String {
+        match (self, precision) {
+            (FieldValue::Float(v), Some(p)) => format!("{:.*}", p, v),
+            (FieldValue::Float(v), None) => v.to_string(),
+            (FieldValue::Int(v), _) => v.to_string(),
+            (FieldValue::Str(v), _) => v.clone(),
+        }
+    }
+
+    fn as_f64(&self) -> Option<f64> {
+        match self {
+            FieldValue::Int(v) => Some(*v as f64),
+            FieldValue::Float(v) => Some(*v),
+            FieldValue::Str(_) => None,
+        }
+    }
+}
+
+/// Comparison operator supported by `{if field op threshold}` conditional blocks.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum CondOp {
+    Gt,
+    Lt,
+    Ge,
+    Le,
+    Eq,
+    Ne,
+}
+
+impl CondOp {
+    fn parse(s: &str) -> Option<Self> {
+        match s {
+            ">" => Some(CondOp::Gt),
+            "<" => Some(CondOp::Lt),
+            ">=" => Some(CondOp::Ge),
+            "<=" => Some(CondOp::Le),
+            "==" => Some(CondOp::Eq),
+            "!=" => Some(CondOp::Ne),
+            _ => None,
+        }
+    }
+
+    #[allow(clippy::float_cmp)]
+    fn apply(&self, lhs: f64, rhs: f64) -> bool {
+        match self {
+            CondOp::Gt => lhs > rhs,
+            CondOp::Lt => lhs < rhs,
+            CondOp::Ge => lhs >= rhs,
+            CondOp::Le => lhs <= rhs,
+            CondOp::Eq => lhs == rhs,
+            CondOp::Ne => lhs != rhs,
+        }
+    }
+}
+
+/// One node of a parsed template: literal text, a field reference with an
+/// optional printf-style precision (`{signal_strength:.3}`), or a conditional
+/// block (`{if opportunities > 5}...{end}`).
+#[derive(Debug, Clone)]
+enum TemplateNode {
+    Text(String),
+    Field { name: String, precision: Option<usize> },
+    Conditional { field: String, op: CondOp, threshold: f64, body: Vec<TemplateNode> },
+}
+
+/// Errors raised while compiling or rendering a template.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TemplateError {
+    UnmatchedEnd,
+    UnterminatedConditional,
+    InvalidTag(String),
+    InvalidCondition(String),
+    UnknownField(String),
+    UnknownPlatform(String),
+    ContentTooLong { rendered_len: usize, max_len: usize },
+}
+
+/// A template compiled once from a remap-style string and reused across renders.
+#[derive(Debug, Clone)]
+pub struct Template {
+    nodes: Vec<TemplateNode>,
+    max_len: Option<usize>,
+}
+
+impl Template {
+    /// Compile `source`, optionally enforcing a platform character limit (e.g. 280
+    /// for twitter) at render time.
+    fn compile(source: &str, max_len: Option<usize>) -> Result<Self, TemplateError> {
+        let mut chars = source.chars().peekable();
+        let nodes = parse_template_nodes(&mut chars, false)?;
+        Ok(Template { nodes, max_len })
+    }
+
+    fn render(&self, data: &QXRResearchData, performance_score: f64) -> Result<String, TemplateError> {
+        let rendered = render_nodes(&self.nodes, data, performance_score)?;
+        if let Some(max_len) = self.max_len {
+            let rendered_len = rendered.chars().count();
+            if rendered_len > max_len {
+                return Err(TemplateError::ContentTooLong { rendered_len, max_len });
+            }
+        }
+        Ok(rendered)
+    }
+}
+
+/// Resolve a `{field}` reference by name against the struct fields of
+/// `QXRResearchData` plus the computed `performance_score`.
+fn resolve_field(data: &QXRResearchData, performance_score: f64, name: &str) -> Option<FieldValue> {
+    match name {
+        "signals" => Some(FieldValue::Int(data.signals as i64)),
+        "opportunities" => Some(FieldValue::Int(data.opportunities as i64)),
+        "signal_strength" => Some(FieldValue::Float(data.signal_strength)),
+        "price_range_min" => Some(FieldValue::Float(data.price_range_min)),
+        "price_range_max" => Some(FieldValue::Float(data.price_range_max)),
+        "max_liquidity" => Some(FieldValue::Int(data.max_liquidity)),
+        "performance_score" => Some(FieldValue::Float(performance_score)),
+        "strategy" => Some(FieldValue::Str(unsafe {
+            CStr::from_ptr(data.strategy_ptr).to_str().unwrap_or("").to_string()
+        })),
+        "timeframe" => Some(FieldValue::Str(unsafe {
+            CStr::from_ptr(data.timeframe_ptr).to_str().unwrap_or("").to_string()
+        })),
+        _ => None,
+    }
+}
+
+/// Parse `{...}` tags and literal text into a node list. `inside_conditional`
+/// tracks whether an `{end}` tag should close this call (nested body) or is an error
+/// (top level).
+fn parse_template_nodes(
+    chars: &mut std::iter::Peekable<std::str::Chars>,
+    inside_conditional: bool,
+) -> Result<Vec<TemplateNode>, TemplateError> {
+    let mut nodes = Vec::new();
+    let mut literal = String::new();
+
+    while let Some(&c) = chars.peek() {
+        if c != '{' {
+            literal.push(c);
+            chars.next();
+            continue;
+        }
+
+        chars.next(); // consume '{'
+        let mut tag = String::new();
+        let mut closed = false;
+        while let Some(&c2) = chars.peek() {
+            chars.next();
+            if c2 == '}' {
+                closed = true;
+                break;
+            }
+            tag.push(c2);
+        }
+        if !closed {
+            return Err(TemplateError::InvalidTag(tag));
+        }
+
+        if tag == "end" {
+            if !literal.is_empty() {
+                nodes.push(TemplateNode::Text(std::mem::take(&mut literal)));
+            }
+            if !inside_conditional {
+                return Err(TemplateError::UnmatchedEnd);
+            }
+            return Ok(nodes);
+        }
+
+        if !literal.is_empty() {
+            nodes.push(TemplateNode::Text(std::mem::take(&mut literal)));
+        }
+
+        if let Some(condition) = tag.strip_prefix("if ") {
+            let parts: Vec<&str> = condition.split_whitespace().collect();
+            if parts.len() != 3 {
+                return Err(TemplateError::InvalidCondition(condition.to_string()));
+            }
+            let op = CondOp::parse(parts[1]).ok_or_else(|| TemplateError::InvalidCondition(condition.to_string()))?;
+            let threshold: f64 = parts[2]
+                .parse()
+                .map_err(|_| TemplateError::InvalidCondition(condition.to_string()))?;
+            let body = parse_template_nodes(chars, true)?;
+            nodes.push(TemplateNode::Conditional { field: parts[0].to_string(), op, threshold, body });
+        } else {
+            let (name, precision) = match tag.split_once(':') {
+                Some((name, spec)) => {
+                    let precision = spec
+                        .strip_prefix('.')
+                        .and_then(|p| p.parse::<usize>().ok())
+                        .ok_or_else(|| TemplateError::InvalidTag(tag.clone()))?;
+                    (name.to_string(), Some(precision))
+                }
+                None => (tag.clone(), None),
+            };
+            nodes.push(TemplateNode::Field { name, precision });
+        }
+    }
+
+    if inside_conditional {
+        return Err(TemplateError::UnterminatedConditional);
+    }
+    if !literal.is_empty() {
+        nodes.push(TemplateNode::Text(literal));
+    }
+    Ok(nodes)
+}
+
+/// Evaluate a parsed node list against `data`, resolving field references and
+/// conditional blocks into a final rendered string.
+fn render_nodes(nodes: &[TemplateNode], data: &QXRResearchData, performance_score: f64) -> Result<String, TemplateError> {
+    let mut out = String::new();
+    for node in nodes {
+        match node {
+            TemplateNode::Text(text) => out.push_str(text),
+            TemplateNode::Field { name, precision } => {
+                let value = resolve_field(data, performance_score, name)
+                    .ok_or_else(|| TemplateError::UnknownField(name.clone()))?;
+                out.push_str(&value.format(*precision));
+            }
+            TemplateNode::Conditional { field, op, threshold, body } => {
+                let value = resolve_field(data, performance_score, field)
+                    .ok_or_else(|| TemplateError::UnknownField(field.clone()))?;
+                let lhs = value.as_f64().ok_or_else(|| TemplateError::InvalidCondition(field.clone()))?;
+                if op.apply(lhs, *threshold) {
+                    out.push_str(&render_nodes(body, data, performance_score)?);
+                }
+            }
+        }
+    }
+    Ok(out)
+}
+
+/// Hardcoded per-platform length limits enforced at render time (mirrors the
+/// limits `generate_social_content` already assumes for twitter).
+fn platform_max_len(platform: &str) -> Option<usize> {
+    match platform {
+        "twitter" => Some(280),
+        _ => None,
+    }
+}
+
+/// Errors raised while encoding or decoding the self-describing wire format.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CodecError {
+    UnexpectedEnd,
+    TagMismatch { expected: u8, found: u8 },
+    InvalidUtf8,
+    UnsupportedSchemaVersion(u8),
+}
+
+const RESEARCH_DATA_SCHEMA_VERSION: u8 = 1;
+const SOCIAL_POST_SCHEMA_VERSION: u8 = 1;
+
+const TAG_I32: u8 = 0x01;
+const TAG_I64: u8 = 0x02;
+const TAG_F64: u8 = 0x03;
+const TAG_STR: u8 = 0x04;
+
+fn write_varint(buf: &mut Vec<u8>, mut value: u64) {
+    loop {
+        let mut byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value != 0 {
+            byte |= 0x80;
+        }
+        buf.push(byte);
+        if value == 0 {
+            break;
+        }
+    }
+}
+
+fn read_varint(bytes: &[u8], pos: &mut usize) -> Result<u64, CodecError> {
+    let mut result: u64 = 0;
+    let mut shift = 0u32;
+    loop {
+        let byte = *bytes.get(*pos).ok_or(CodecError::UnexpectedEnd)?;
+        *pos += 1;
+        result |= ((byte & 0x7f) as u64) << shift;
+        if byte & 0x80 == 0 {
+            break;
+        }
+        shift += 7;
+    }
+    Ok(result)
+}
+
+fn expect_tag(bytes: &[u8], pos: &mut usize, expected: u8) -> Result<(), CodecError> {
+    let tag = *bytes.get(*pos).ok_or(CodecError::UnexpectedEnd)?;
+    *pos += 1;
+    if tag != expected {
+        return Err(CodecError::TagMismatch { expected, found: tag });
+    }
+    Ok(())
+}
+
+fn write_i32(buf: &mut Vec<u8>, value: i32) {
+    buf.push(TAG_I32);
+    buf.extend_from_slice(&value.to_le_bytes());
+}
+
+fn read_i32(bytes: &[u8], pos: &mut usize) -> Result<i32, CodecError> {
+    expect_tag(bytes, pos, TAG_I32)?;
+    let slice = bytes.get(*pos..*pos + 4).ok_or(CodecError::UnexpectedEnd)?;
+    *pos += 4;
+    Ok(i32::from_le_bytes(slice.try_into().unwrap()))
+}
+
+fn write_i64(buf: &mut Vec<u8>, value: i64) {
+    buf.push(TAG_I64);
+    buf.extend_from_slice(&value.to_le_bytes());
+}
+
+fn read_i64(bytes: &[u8], pos: &mut usize) -> Result<i64, CodecError> {
+    expect_tag(bytes, pos, TAG_I64)?;
+    let slice = bytes.get(*pos..*pos + 8).ok_or(CodecError::UnexpectedEnd)?;
+    *pos += 8;
+    Ok(i64::from_le_bytes(slice.try_into().unwrap()))
+}
+
+fn write_f64(buf: &mut Vec<u8>, value: f64) {
+    buf.push(TAG_F64);
+    buf.extend_from_slice(&value.to_le_bytes());
+}
+
+fn read_f64(bytes: &[u8], pos: &mut usize) -> Result<f64, CodecError> {
+    expect_tag(bytes, pos, TAG_F64)?;
+    let slice = bytes.get(*pos..*pos + 8).ok_or(CodecError::UnexpectedEnd)?;
+    *pos += 8;
+    Ok(f64::from_le_bytes(slice.try_into().unwrap()))
+}
+
+fn write_str(buf: &mut Vec<u8>, value: &str) {
+    buf.push(TAG_STR);
+    write_varint(buf, value.len() as u64);
+    buf.extend_from_slice(value.as_bytes());
+}
+
+fn read_str(bytes: &[u8], pos: &mut usize) -> Result<String, CodecError> {
+    expect_tag(bytes, pos, TAG_STR)?;
+    let len = read_varint(bytes, pos)? as usize;
+    // `len` comes straight from the wire; a corrupted/malicious varint must
+    // not be allowed to overflow `*pos + len` or allocate an unbounded
+    // `Vec<u8>` — bound it against what's actually left in `bytes` first.
+    let end = pos.checked_add(len).ok_or(CodecError::UnexpectedEnd)?;
+    let slice = bytes.get(*pos..end).ok_or(CodecError::UnexpectedEnd)?;
+    *pos = end;
+    String::from_utf8(slice.to_vec()).map_err(|_| CodecError::InvalidUtf8)
+}
+
+/// Allocate a null-terminated C string the same way `qxr_bridge_alloc_string`
+/// does, so decoded pointers can be released with `qxr_bridge_free_string`.
+fn alloc_c_string(s: &str) -> *mut c_char {
+    let bytes = s.as_bytes();
+    let layout = std::alloc::Layout::array::<u8>(bytes.len() + 1).unwrap();
+    unsafe {
+        let ptr = std::alloc::alloc(layout) as *mut c_char;
+        if !ptr.is_null() {
+            std::ptr::copy_nonoverlapping(bytes.as_ptr(), ptr as *mut u8, bytes.len());
+            *ptr.add(bytes.len()) = 0;
+        }
+        ptr
+    }
+}
+
+unsafe fn cstr_to_string(ptr: *const c_char) -> Result<String, CodecError> {
+    CStr::from_ptr(ptr).to_str().map(|s| s.to_string()).map_err(|_| CodecError::InvalidUtf8)
+}
+
+/// Encode a `QXRResearchData` into the self-describing wire format: a leading
+/// schema version byte followed by `(type_tag, payload)` fields in struct order.
+pub fn encode_research_data(data: &QXRResearchData) -> Result<Vec<u8>, CodecError> {
+    let strategy = unsafe { cstr_to_string(data.strategy_ptr)? };
+    let timeframe = unsafe { cstr_to_string(data.timeframe_ptr)? };
+
+    let mut buf = Vec::new();
+    buf.push(RESEARCH_DATA_SCHEMA_VERSION);
+    write_i32(&mut buf, data.signals);
+    write_i32(&mut buf, data.opportunities);
+    write_f64(&mut buf, data.signal_strength);
+    write_f64(&mut buf, data.price_range_min);
+    write_f64(&mut buf, data.price_range_max);
+    write_i64(&mut buf, data.max_liquidity);
+    write_str(&mut buf, &strategy);
+    write_str(&mut buf, &timeframe);
+    Ok(buf)
+}
+
+/// Decode a `QXRResearchData` previously produced by `encode_research_data`.
+/// String fields are re-allocated with `alloc_c_string`; the caller owns the
+/// returned pointers and must release them with `qxr_bridge_free_string`.
+pub fn decode_research_data(bytes: &[u8]) -> Result<QXRResearchData, CodecError> {
+    let mut pos = 0usize;
+    let version = *bytes.first().ok_or(CodecError::UnexpectedEnd)?;
+    pos += 1;
+    if version != RESEARCH_DATA_SCHEMA_VERSION {
+        return Err(CodecError::UnsupportedSchemaVersion(version));
+    }
+
+    let signals = read_i32(bytes, &mut pos)?;
+    let opportunities = read_i32(bytes, &mut pos)?;
+    let signal_strength = read_f64(bytes, &mut pos)?;
+    let price_range_min = read_f64(bytes, &mut pos)?;
+    let price_range_max = read_f64(bytes, &mut pos)?;
+    let max_liquidity = read_i64(bytes, &mut pos)?;
+    let strategy = read_str(bytes, &mut pos)?;
+    let timeframe = read_str(bytes, &mut pos)?;
+
+    Ok(QXRResearchData {
+        signals,
+        opportunities,
+        signal_strength,
+        price_range_min,
+        price_range_max,
+        max_liquidity,
+        strategy_len: strategy.len(),
+        strategy_ptr: alloc_c_string(&strategy),
+        timeframe_len: timeframe.len(),
+        timeframe_ptr: alloc_c_string(&timeframe),
+    })
+}
+
+/// Encode a `QXRSocialPost` into the self-describing wire format.
+pub fn encode_social_post(post: &QXRSocialPost) -> Result<Vec<u8>, CodecError> {
+    let platform = unsafe { cstr_to_string(post.platform_ptr)? };
+    let content = unsafe { cstr_to_string(post.content_ptr)? };
+    let mut hashtags = Vec::with_capacity(post.hashtags_count);
+    for i in 0..post.hashtags_count {
+        let tag_ptr = unsafe { *post.hashtags_ptr.add(i) };
+        hashtags.push(unsafe { cstr_to_string(tag_ptr)? });
+    }
+
+    let mut buf = Vec::new();
+    buf.push(SOCIAL_POST_SCHEMA_VERSION);
+    write_str(&mut buf, &platform);
+    write_str(&mut buf, &content);
+    write_i32(&mut buf, hashtags.len() as i32);
+    for hashtag in &hashtags {
+        write_str(&mut buf, hashtag);
+    }
+    write_f64(&mut buf, post.engagement_score);
+    Ok(buf)
+}
+
+/// Decode a `QXRSocialPost` previously produced by `encode_social_post`. String
+/// and hashtag-array pointers are re-allocated; the caller owns them.
+pub fn decode_social_post(bytes: &[u8]) -> Result<QXRSocialPost, CodecError> {
+    let mut pos = 0usize;
+    let version = *bytes.first().ok_or(CodecError::UnexpectedEnd)?;
+    pos += 1;
+    if version != SOCIAL_POST_SCHEMA_VERSION {
+        return Err(CodecError::UnsupportedSchemaVersion(version));
+    }
+
+    let platform = read_str(bytes, &mut pos)?;
+    let content = read_str(bytes, &mut pos)?;
+    let hashtags_count_raw = read_i32(bytes, &mut pos)?;
+    // `hashtags_count_raw` comes straight from the wire: a negative value
+    // would wrap to a huge `usize` on cast, and even a valid-looking huge
+    // positive value must not reach `Vec::with_capacity` before it's bounded
+    // against what could actually fit in the remaining buffer (an empty
+    // hashtag string takes two bytes, its tag and a zero varint length, so
+    // one byte per hashtag is a safe upper bound on the count).
+    if hashtags_count_raw < 0 || hashtags_count_raw as usize > bytes.len().saturating_sub(pos) {
+        return Err(CodecError::UnexpectedEnd);
+    }
+    let hashtags_count = hashtags_count_raw as usize;
+    let mut hashtag_ptrs: Vec<*mut c_char> = Vec::with_capacity(hashtags_count);
+    for _ in 0..hashtags_count {
+        let hashtag = read_str(bytes, &mut pos)?;
+        hashtag_ptrs.push(alloc_c_string(&hashtag));
+    }
+    let engagement_score = read_f64(bytes, &mut pos)?;
+
+    let hashtags_ptr = hashtag_ptrs.as_mut_ptr();
+    std::mem::forget(hashtag_ptrs);
+
+    Ok(QXRSocialPost {
+        platform_len: platform.len(),
+        platform_ptr: alloc_c_string(&platform),
+        content_len: content.len(),
+        content_ptr: alloc_c_string(&content),
+        hashtags_count,
+        hashtags_ptr,
+        engagement_score,
+    })
+}
+
+/// Per-request publish status reported back across the FFI boundary by
+/// `qxr_bridge_poll`.
+#[derive(Debug, Clone, PartialEq)]
+enum PublishStatus {
+    Pending,
+    Completed { http_status: u16 },
+    Failed,
+}
+
+/// Fixed worker count, so a burst of `qxr_bridge_publish` calls can't open
+/// an unbounded number of sockets/threads.
+const PUBLISH_WORKER_COUNT: usize = 4;
+
+/// Timeout applied separately to a publish's TCP connect and to each socket
+/// read and write; exceeding any one of them fails the request. Host name
+/// resolution and the publish as a whole have no overall time limit.
+const PUBLISH_IO_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+
+/// A queued publish request waiting for a worker thread to pick it up.
+struct PublishJob {
+    request_id: u64,
+    endpoint_url: String,
+    auth_token: String,
+    body: String,
+}
+
+/// Publishes queued posts over a bounded pool of `PUBLISH_WORKER_COUNT`
+/// worker threads. KNOWN LIMITATION: this is plaintext HTTP/1.1 only, not
+/// QUIC/HTTP3 — there's no manifest here to pull in a TLS/QUIC dependency,
+/// so `https://` endpoints are rejected outright rather than downgraded.
+struct PublishTransport {
+    results: Arc<Mutex<HashMap<u64, PublishStatus>>>,
+    job_sender: mpsc::Sender<PublishJob>,
+    next_request_id: u64,
+}
+
+impl PublishTransport {
+    fn new() -> Self {
+        let results: Arc<Mutex<HashMap<u64, PublishStatus>>> = Arc::new(Mutex::new(HashMap::new()));
+        let (job_sender, job_receiver) = mpsc::channel::<PublishJob>();
+        let job_receiver = Arc::new(Mutex::new(job_receiver));
+
+        for _ in 0..PUBLISH_WORKER_COUNT {
+            let job_receiver = Arc::clone(&job_receiver);
+            let results = Arc::clone(&results);
+            thread::spawn(move || loop {
+                let job = job_receiver.lock().unwrap().recv();
+                let job = match job {
+                    Ok(job) => job,
+                    Err(_) => break, // all senders dropped; shut the worker down
+                };
+                let status = match send_http_post(&job.endpoint_url, &job.auth_token, &job.body) {
+                    Ok(http_status) => PublishStatus::Completed { http_status },
+                    Err(_) => PublishStatus::Failed,
+                };
+                results.lock().unwrap().insert(job.request_id, status);
+            });
+        }
+
+        PublishTransport { results, job_sender, next_request_id: 1 }
+    }
+
+    /// Queue `body` for delivery to `endpoint_url` with a bearer `auth_token`
+    /// on a worker thread, and return the request id used to poll for
+    /// completion.
+    fn publish(&mut self, endpoint_url: String, auth_token: String, body: String) -> u64 {
+        let request_id = self.next_request_id;
+        self.next_request_id += 1;
+
+        self.results.lock().unwrap().insert(request_id, PublishStatus::Pending);
+        // The only failure mode is all workers having panicked; the job is
+        // simply dropped and the request stays `Pending` forever, which is
+        // preferable to panicking the caller's FFI call.
+        let _ = self.job_sender.send(PublishJob { request_id, endpoint_url, auth_token, body });
+
+        request_id
+    }
+
+    fn poll(&self, request_id: u64) -> Option<PublishStatus> {
+        self.results.lock().unwrap().get(&request_id).cloned()
+    }
+}
+
+/// Send a single HTTP/1.1 POST of `body` to `endpoint_url` with a bearer auth
+/// token, returning the parsed response status code. Only `http://` is
+/// accepted; `https://` is rejected rather than downgraded to cleartext.
+fn send_http_post(endpoint_url: &str, auth_token: &str, body: &str) -> std::io::Result<u16> {
+    if auth_token.contains(|c: char| c.is_control()) {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "auth_token contains control characters and cannot be placed in an HTTP header",
+        ));
+    }
+
+    let without_scheme = endpoint_url.strip_prefix("http://").ok_or_else(|| {
+        std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            format!(
+                "refusing to publish to '{}': only http:// endpoints are supported (no TLS/QUIC stack is available without adding a dependency)",
+                endpoint_url
+            ),
+        )
+    })?;
+    let (authority, path) = match without_scheme.find('/') {
+        Some(idx) => (&without_scheme[..idx], &without_scheme[idx..]),
+        None => (without_scheme, "/"),
+    };
+    let (host, port) = match authority.strip_prefix('[') {
+        // IPv6 literal, e.g. "[::1]:8080" or "[::1]" — the bracketed form
+        // can't be split on the last ':' like a normal host:port pair since
+        // the address itself is full of colons.
+        Some(rest) => match rest.split_once(']') {
+            Some((host, suffix)) => (
+                host,
+                suffix
+                    .strip_prefix(':')
+                    .and_then(|p| p.parse::<u16>().ok())
+                    .unwrap_or(80),
+            ),
+            None => (authority, 80),
+        },
+        None => match authority.rsplit_once(':') {
+            Some((host, port)) => (host, port.parse::<u16>().unwrap_or(80)),
+            None => (authority, 80),
+        },
+    };
+
+    let addr = (host, port)
+        .to_socket_addrs()?
+        .next()
+        .ok_or_else(|| std::io::Error::new(std::io::ErrorKind::NotFound, "could not resolve endpoint host"))?;
+
+    let mut stream = TcpStream::connect_timeout(&addr, PUBLISH_IO_TIMEOUT)?;
+    stream.set_read_timeout(Some(PUBLISH_IO_TIMEOUT))?;
+    stream.set_write_timeout(Some(PUBLISH_IO_TIMEOUT))?;
+
+    let request = format!(
+        "POST {path} HTTP/1.1\r\nHost: {host}\r\nAuthorization: Bearer {auth_token}\r\nContent-Length: {len}\r\nConnection: close\r\n\r\n{body}",
+        path = path,
+        host = host,
+        auth_token = auth_token,
+        len = body.len(),
+        body = body
+    );
+    stream.write_all(request.as_bytes())?;
+
+    let mut response = String::new();
+    stream.read_to_string(&mut response)?;
+
+    let status_line = response
+        .lines()
+        .next()
+        .ok_or_else(|| std::io::Error::new(std::io::ErrorKind::InvalidData, "empty response"))?;
+    status_line
+        .split_whitespace()
+        .nth(1)
+        .and_then(|code| code.parse::<u16>().ok())
+        .ok_or_else(|| std::io::Error::new(std::io::ErrorKind::InvalidData, "malformed status line"))
+}
+
+/// Errors raised by the additive secret-sharing subsystem.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ShareError {
+    InvalidPartyCount,
+}
+
+/// Prime modulus for additive secret sharing: the Mersenne prime 2^61 - 1.
+/// Shares and partial sums are elements of Z/pZ and wrap modulo `p`.
+const SHARE_MODULUS: u64 = 2_305_843_009_213_693_951;
+
+/// Scale factor for encoding a floating-point score as a fixed-point integer
+/// before sharing.
+const FIXED_POINT_SCALE: f64 = 1_000_000.0;
+
+/// Encode a floating-point score as the fixed-point `i64` that
+/// `qxr_share_value` expects. Exposed over FFI as `qxr_encode_fixedpoint`.
+pub fn encode_fixedpoint(value: f64) -> i64 {
+    (value * FIXED_POINT_SCALE).round() as i64
+}
+
+/// Inverse of `encode_fixedpoint`; also what `reconstruct` uses internally.
+pub fn decode_fixedpoint(value: i64) -> f64 {
+    value as f64 / FIXED_POINT_SCALE
+}
+
+/// Map a value in `[0, SHARE_MODULUS)` back onto the signed range a
+/// fixed-point score was encoded from.
+fn modulus_to_signed(value: u64) -> i64 {
+    let half = SHARE_MODULUS / 2;
+    if value > half {
+        -((SHARE_MODULUS - value) as i64)
+    } else {
+        value as i64
+    }
+}
+
+/// A cryptographically random element of `[0, SHARE_MODULUS)`. Reads entropy
+/// from `/dev/urandom` directly, since `RandomState` is not a CSPRNG and no
+/// `rand`/`getrandom` crate is pullable without a `Cargo.toml`.
+fn random_share_component() -> u64 {
+    use std::fs::File;
+
+    let mut entropy = [0u8; 8];
+    File::open("/dev/urandom")
+        .and_then(|mut urandom| urandom.read_exact(&mut entropy))
+        .expect("failed to read entropy from /dev/urandom for secret sharing");
+    u64::from_le_bytes(entropy) % SHARE_MODULUS
+}
+
+/// Split a fixed-point-encoded secret into `num_parties` additive shares over
+/// `Z/SHARE_MODULUS`: `num_parties - 1` uniformly random shares plus a final
+/// share set so all shares sum to the secret mod `SHARE_MODULUS`.
+pub fn share_value(value_fixedpoint: i64, num_parties: usize) -> Result<Vec<u64>, ShareError> {
+    if num_parties == 0 {
+        return Err(ShareError::InvalidPartyCount);
+    }
+
+    let modulus = SHARE_MODULUS as i128;
+    let secret = (((value_fixedpoint as i128) % modulus) + modulus) % modulus;
+
+    let mut shares = Vec::with_capacity(num_parties);
+    let mut running_sum: i128 = 0;
+    for _ in 0..num_parties - 1 {
+        let share = random_share_component();
+        running_sum = (running_sum + share as i128) % modulus;
+        shares.push(share);
+    }
+
+    let last_share = (((secret - running_sum) % modulus) + modulus) % modulus;
+    shares.push(last_share as u64);
+    Ok(shares)
+}
+
+/// Reconstruct the aggregate fixed-point score from each party's partial sum of
+/// shares (each party locally sums the shares it holds across a batch before
+/// calling this), then decode it back to a floating-point score.
+pub fn reconstruct(party_partial_sums: &[u64]) -> f64 {
+    let modulus = SHARE_MODULUS as u128;
+    let sum = party_partial_sums
+        .iter()
+        .fold(0u128, |acc, &partial_sum| (acc + partial_sum as u128) % modulus);
+    decode_fixedpoint(modulus_to_signed(sum as u64))
+}
+
+/// Per-span call statistics accumulated across the lifetime of a bridge.
+#[derive(Debug, Clone, Default)]
+pub struct SpanAggregate {
+    pub call_count: u64,
+    pub total_nanos: u64,
+    pub min_nanos: u64,
+    pub max_nanos: u64,
+}
+
+thread_local! {
+    /// Stack of in-flight spans for the current thread. A bridge may be driven
+    /// from several native threads, so the stack itself stays thread-local while
+    /// the accumulated aggregates live on the bridge that `exit_span` is called with.
+    static SPAN_STACK: RefCell<Vec<(&'static str, Instant)>> = RefCell::new(Vec::new());
+}
+
 /// Bridge context for managing memory and state
 pub struct QXRBridge {
     pub research_data: Option<QXRResearchData>,
     pub social_posts: Vec<QXRSocialPost>,
     pub memory_allocations: Vec<*mut c_void>,
+    pub span_aggregates: HashMap<&'static str, SpanAggregate>,
+    templates: HashMap<String, Template>,
+    publish_transport: PublishTransport,
 }
 
 impl QXRBridge {
@@ -52,24 +820,103 @@ impl QXRBridge {
             research_data: None,
             social_posts: Vec::new(),
             memory_allocations: Vec::new(),
+            span_aggregates: HashMap::new(),
+            templates: HashMap::new(),
+            publish_transport: PublishTransport::new(),
+        }
+    }
+
+    /// Publish a rendered post to `endpoint_url`, authenticating with
+    /// `auth_token`, on a background thread. Returns the request id to pass to
+    /// `poll_publish`.
+    pub fn publish_post(&mut self, post: &QXRSocialPost, endpoint_url: &str, auth_token: &str) -> u64 {
+        let content = unsafe { CStr::from_ptr(post.content_ptr).to_str().unwrap_or("").to_string() };
+        self.publish_transport.publish(endpoint_url.to_string(), auth_token.to_string(), content)
+    }
+
+    /// Non-blocking lookup of a publish request's current status.
+    fn poll_publish(&self, request_id: u64) -> Option<PublishStatus> {
+        self.publish_transport.poll(request_id)
+    }
+
+    /// Compile `template_str` once and register it for `platform`, replacing any
+    /// existing template for that platform.
+    pub fn register_template(&mut self, platform: &str, template_str: &str) -> Result<(), TemplateError> {
+        let template = Template::compile(template_str, platform_max_len(platform))?;
+        self.templates.insert(platform.to_string(), template);
+        Ok(())
+    }
+
+    /// Render the template registered for `platform` against `data`.
+    pub fn render_template(&mut self, data: &QXRResearchData, platform: &str) -> Result<String, TemplateError> {
+        let performance_score = self.process_research_data(data);
+        let template = self
+            .templates
+            .get(platform)
+            .ok_or_else(|| TemplateError::UnknownPlatform(platform.to_string()))?;
+        template.render(data, performance_score)
+    }
+
+    /// Push a span onto the current thread's span stack.
+    fn enter_span(name: &'static str) {
+        SPAN_STACK.with(|stack| stack.borrow_mut().push((name, Instant::now())));
+    }
+
+    /// Pop the innermost span and fold its elapsed time into this bridge's aggregates.
+    fn exit_span(&mut self) {
+        let (name, start) = SPAN_STACK
+            .with(|stack| stack.borrow_mut().pop())
+            .expect("exit_span called without a matching enter_span");
+        let elapsed_nanos = start.elapsed().as_nanos() as u64;
+
+        let aggregate = self.span_aggregates.entry(name).or_insert_with(SpanAggregate::default);
+        aggregate.min_nanos = if aggregate.call_count == 0 {
+            elapsed_nanos
+        } else {
+            aggregate.min_nanos.min(elapsed_nanos)
+        };
+        aggregate.max_nanos = aggregate.max_nanos.max(elapsed_nanos);
+        aggregate.total_nanos += elapsed_nanos;
+        aggregate.call_count += 1;
+    }
+
+    /// Serialize the accumulated span aggregates as a small hand-rolled JSON object,
+    /// e.g. `{"process_research_data":{"call_count":3,"total_nanos":410,"min_nanos":90,"max_nanos":180}}`.
+    fn timings_json(&self) -> String {
+        let mut json = String::from("{");
+        for (i, (name, aggregate)) in self.span_aggregates.iter().enumerate() {
+            if i > 0 {
+                json.push(',');
+            }
+            json.push_str(&format!(
+                "\"{}\":{{\"call_count\":{},\"total_nanos\":{},\"min_nanos\":{},\"max_nanos\":{}}}",
+                name, aggregate.call_count, aggregate.total_nanos, aggregate.min_nanos, aggregate.max_nanos
+            ));
         }
+        json.push('}');
+        json
     }
 
     /// Process research data with high-performance calculations
     pub fn process_research_data(&mut self, data: &QXRResearchData) -> f64 {
+        Self::enter_span("process_research_data");
+
         // High-performance signal processing
         let base_score = data.signals as f64 * data.signal_strength;
         let liquidity_factor = (data.max_liquidity as f64).ln() / 10.0;
         let opportunity_multiplier = 1.0 + (data.opportunities as f64 / 100.0);
-        
-        base_score * liquidity_factor * opportunity_multiplier
+        let result = base_score * liquidity_factor * opportunity_multiplier;
+
+        self.exit_span();
+        result
     }
 
     /// Generate optimized social media content
     pub fn generate_social_content(&mut self, research_data: &QXRResearchData, platform: &str) -> String {
+        Self::enter_span("generate_social_content");
         let performance_score = self.process_research_data(research_data);
-        
-        match platform {
+
+        let content = match platform {
             "linkedin" => format!(
                 "🚀 QXR Research Update: {} signals detected with {:.3} strength. \
                 Performance score: {:.2}. {} opportunities identified in {}.",
@@ -88,7 +935,10 @@ impl QXRBridge {
                 unsafe { CStr::from_ptr(research_data.timeframe_ptr).to_str().unwrap_or("24h") }
             ),
             _ => format!("QXR Analysis: {} signals, performance {:.2}", research_data.signals, performance_score)
-        }
+        };
+
+        self.exit_span();
+        content
     }
 }
 
@@ -175,54 +1025,435 @@ pub extern "C" fn qxr_bridge_batch_process(
         return -1;
     }
     
+    QXRBridge::enter_span("qxr_bridge_batch_process");
     unsafe {
         let bridge_ref = &mut *bridge;
         let data_slice = slice::from_raw_parts(data_array, data_count);
         let results_slice = slice::from_raw_parts_mut(results, data_count);
-        
+
         for (i, data) in data_slice.iter().enumerate() {
             results_slice[i] = bridge_ref.process_research_data(data);
         }
-        
+
+        bridge_ref.exit_span();
         data_count as c_int
     }
 }
 
-/// Memory allocation helper for Python integration
+/// Serialize per-span call counts and elapsed-time aggregates (in nanoseconds) as
+/// JSON so callers can profile signal processing versus content formatting
+/// separately instead of only seeing end-to-end wall time.
 #[no_mangle]
-pub extern "C" fn qxr_bridge_alloc_string(len: usize) -> *mut c_char {
-    let layout = std::alloc::Layout::array::<u8>(len + 1).unwrap();
+pub extern "C" fn qxr_bridge_timings(
+    bridge: *mut QXRBridge,
+    out_json: *mut c_char,
+    buf_size: usize
+) -> c_int {
+    if bridge.is_null() || out_json.is_null() {
+        return -1;
+    }
+
     unsafe {
-        let ptr = std::alloc::alloc(layout) as *mut c_char;
-        if !ptr.is_null() {
-            *ptr.add(len) = 0; // Null terminate
+        let bridge_ref = &*bridge;
+        let json = bridge_ref.timings_json();
+        let json_bytes = json.as_bytes();
+
+        if json_bytes.len() >= buf_size {
+            return -2; // Buffer too small
         }
-        ptr
+
+        std::ptr::copy_nonoverlapping(
+            json_bytes.as_ptr(),
+            out_json as *mut u8,
+            json_bytes.len()
+        );
+
+        *(out_json.add(json_bytes.len())) = 0;
+
+        json_bytes.len() as c_int
     }
 }
 
+/// Compile and register a remap-style template string for `platform`, so
+/// `qxr_bridge_render` can produce runtime-configurable content without a rebuild.
+/// Returns 0 on success, -1 on a null argument, -3 on a template compile error.
 #[no_mangle]
-pub extern "C" fn qxr_bridge_free_string(ptr: *mut c_char) {
-    if !ptr.is_null() {
-        unsafe {
-            let layout = std::alloc::Layout::array::<u8>(1).unwrap();
-            std::alloc::dealloc(ptr as *mut u8, layout);
+pub extern "C" fn qxr_bridge_register_template(
+    bridge: *mut QXRBridge,
+    platform: *const c_char,
+    template_str: *const c_char
+) -> c_int {
+    if bridge.is_null() || platform.is_null() || template_str.is_null() {
+        return -1;
+    }
+
+    unsafe {
+        let bridge_ref = &mut *bridge;
+        let platform_str = match CStr::from_ptr(platform).to_str() {
+            Ok(s) => s,
+            Err(_) => return -1,
+        };
+        let template_str = match CStr::from_ptr(template_str).to_str() {
+            Ok(s) => s,
+            Err(_) => return -1,
+        };
+
+        match bridge_ref.register_template(platform_str, template_str) {
+            Ok(()) => 0,
+            Err(_) => -3,
         }
     }
 }
 
-/// Get bridge version info
+/// Render the template registered for `platform` against `data`. Returns the
+/// rendered length on success, -2 if `output_buffer` is too small, -3 if no
+/// template is registered for `platform` or the rendered content exceeds the
+/// platform's length limit.
 #[no_mangle]
-pub extern "C" fn qxr_bridge_version() -> *const c_char {
-    "QXR Bridge v0.1.0\0".as_ptr() as *const c_char
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use std::ffi::CString;
-
-    #[test]
+pub extern "C" fn qxr_bridge_render(
+    bridge: *mut QXRBridge,
+    data: *const QXRResearchData,
+    platform: *const c_char,
+    output_buffer: *mut c_char,
+    buffer_size: usize
+) -> c_int {
+    if bridge.is_null() || data.is_null() || platform.is_null() || output_buffer.is_null() {
+        return -1;
+    }
+
+    unsafe {
+        let bridge_ref = &mut *bridge;
+        let data_ref = &*data;
+        let platform_str = match CStr::from_ptr(platform).to_str() {
+            Ok(s) => s,
+            Err(_) => return -1,
+        };
+
+        let rendered = match bridge_ref.render_template(data_ref, platform_str) {
+            Ok(content) => content,
+            Err(_) => return -3,
+        };
+        let rendered_bytes = rendered.as_bytes();
+
+        if rendered_bytes.len() >= buffer_size {
+            return -2; // Buffer too small
+        }
+
+        std::ptr::copy_nonoverlapping(
+            rendered_bytes.as_ptr(),
+            output_buffer as *mut u8,
+            rendered_bytes.len()
+        );
+
+        *(output_buffer.add(rendered_bytes.len())) = 0;
+
+        rendered_bytes.len() as c_int
+    }
+}
+
+/// Encode `data` into the self-describing wire format so Python can
+/// serialize/deserialize it without matching Rust's native struct layout.
+/// Returns the encoded length on success, -2 if `out` is too small, -3 on
+/// invalid UTF-8 in the source string fields.
+#[no_mangle]
+pub extern "C" fn qxr_encode_research_data(
+    data: *const QXRResearchData,
+    out: *mut c_char,
+    size: usize
+) -> c_int {
+    if data.is_null() || out.is_null() {
+        return -1;
+    }
+
+    unsafe {
+        let data_ref = &*data;
+        let encoded = match encode_research_data(data_ref) {
+            Ok(bytes) => bytes,
+            Err(_) => return -3,
+        };
+
+        if encoded.len() > size {
+            return -2; // Buffer too small
+        }
+
+        std::ptr::copy_nonoverlapping(encoded.as_ptr(), out as *mut u8, encoded.len());
+        encoded.len() as c_int
+    }
+}
+
+/// Decode a `QXRResearchData` previously produced by `qxr_encode_research_data`
+/// into `out_data`. The decoded `strategy_ptr`/`timeframe_ptr` are heap-allocated
+/// and must be released with `qxr_bridge_free_string`. Returns 0 on success, -3
+/// on a malformed buffer or schema version mismatch.
+#[no_mangle]
+pub extern "C" fn qxr_decode_research_data(
+    bytes: *const c_char,
+    len: usize,
+    out_data: *mut QXRResearchData
+) -> c_int {
+    if bytes.is_null() || out_data.is_null() {
+        return -1;
+    }
+
+    unsafe {
+        let byte_slice = slice::from_raw_parts(bytes as *const u8, len);
+        match decode_research_data(byte_slice) {
+            Ok(decoded) => {
+                *out_data = decoded;
+                0
+            }
+            Err(_) => -3,
+        }
+    }
+}
+
+/// Encode `post` into the self-describing wire format. Returns the encoded
+/// length on success, -2 if `out` is too small, -3 on invalid UTF-8.
+#[no_mangle]
+pub extern "C" fn qxr_encode_social_post(
+    post: *const QXRSocialPost,
+    out: *mut c_char,
+    size: usize
+) -> c_int {
+    if post.is_null() || out.is_null() {
+        return -1;
+    }
+
+    unsafe {
+        let post_ref = &*post;
+        let encoded = match encode_social_post(post_ref) {
+            Ok(bytes) => bytes,
+            Err(_) => return -3,
+        };
+
+        if encoded.len() > size {
+            return -2; // Buffer too small
+        }
+
+        std::ptr::copy_nonoverlapping(encoded.as_ptr(), out as *mut u8, encoded.len());
+        encoded.len() as c_int
+    }
+}
+
+/// Decode a `QXRSocialPost` previously produced by `qxr_encode_social_post` into
+/// `out_post`. Returns 0 on success, -3 on a malformed buffer or schema version
+/// mismatch.
+#[no_mangle]
+pub extern "C" fn qxr_decode_social_post(
+    bytes: *const c_char,
+    len: usize,
+    out_post: *mut QXRSocialPost
+) -> c_int {
+    if bytes.is_null() || out_post.is_null() {
+        return -1;
+    }
+
+    unsafe {
+        let byte_slice = slice::from_raw_parts(bytes as *const u8, len);
+        match decode_social_post(byte_slice) {
+            Ok(decoded) => {
+                *out_post = decoded;
+                0
+            }
+            Err(_) => -3,
+        }
+    }
+}
+
+/// Free every heap-allocated pointer owned by a `QXRSocialPost` produced by
+/// `qxr_decode_social_post`, including the `hashtags_ptr` array itself. Safe
+/// to call on a post whose pointers are already null.
+#[no_mangle]
+pub extern "C" fn qxr_bridge_free_social_post(post: *mut QXRSocialPost) {
+    if post.is_null() {
+        return;
+    }
+
+    unsafe {
+        let post_ref = &mut *post;
+
+        qxr_bridge_free_string(post_ref.platform_ptr);
+        qxr_bridge_free_string(post_ref.content_ptr);
+
+        if !post_ref.hashtags_ptr.is_null() {
+            for i in 0..post_ref.hashtags_count {
+                qxr_bridge_free_string(*post_ref.hashtags_ptr.add(i));
+            }
+            drop(Vec::from_raw_parts(post_ref.hashtags_ptr, post_ref.hashtags_count, post_ref.hashtags_count));
+        }
+
+        post_ref.platform_ptr = std::ptr::null_mut();
+        post_ref.content_ptr = std::ptr::null_mut();
+        post_ref.hashtags_ptr = std::ptr::null_mut();
+        post_ref.hashtags_count = 0;
+    }
+}
+
+/// Encode `value` (e.g. the `f64` score `process_research_data` returns) as
+/// the fixed-point `i64` that `qxr_share_value` expects, so callers don't have
+/// to hardcode `FIXED_POINT_SCALE` themselves.
+#[no_mangle]
+pub extern "C" fn qxr_encode_fixedpoint(value: c_double) -> i64 {
+    encode_fixedpoint(value)
+}
+
+/// Inverse of `qxr_encode_fixedpoint`.
+#[no_mangle]
+pub extern "C" fn qxr_decode_fixedpoint(value_fixedpoint: i64) -> c_double {
+    decode_fixedpoint(value_fixedpoint)
+}
+
+/// Split a fixed-point-encoded scalar into `num_parties` additive shares over
+/// `Z/SHARE_MODULUS`, writing them to `out_shares` (which must hold at least
+/// `num_parties` `u64`s). Returns `num_parties` on success, -1 on a null
+/// argument or zero party count.
+#[no_mangle]
+pub extern "C" fn qxr_share_value(
+    value_fixedpoint: i64,
+    num_parties: usize,
+    out_shares: *mut u64
+) -> c_int {
+    if out_shares.is_null() {
+        return -1;
+    }
+
+    match share_value(value_fixedpoint, num_parties) {
+        Ok(shares) => {
+            unsafe {
+                let out_slice = slice::from_raw_parts_mut(out_shares, shares.len());
+                out_slice.copy_from_slice(&shares);
+            }
+            shares.len() as c_int
+        }
+        Err(_) => -1,
+    }
+}
+
+/// Reconstruct the aggregate score from each party's locally-summed partial
+/// sum of shares and decode it back to a floating-point value in `out`.
+/// Returns 0 on success, -1 on a null argument.
+#[no_mangle]
+pub extern "C" fn qxr_reconstruct(
+    shares: *const u64,
+    n: usize,
+    out: *mut c_double
+) -> c_int {
+    if shares.is_null() || out.is_null() {
+        return -1;
+    }
+
+    unsafe {
+        let shares_slice = slice::from_raw_parts(shares, n);
+        *out = reconstruct(shares_slice);
+    }
+    0
+}
+
+/// Publish `post`'s content to `endpoint_url` (bearer-authenticated with
+/// `auth_token`) on a background thread and return a request id, or -1 on a
+/// null argument. Poll for completion with `qxr_bridge_poll` instead of
+/// blocking here, keeping the C ABI synchronous-friendly.
+#[no_mangle]
+pub extern "C" fn qxr_bridge_publish(
+    bridge: *mut QXRBridge,
+    post: *const QXRSocialPost,
+    endpoint_url: *const c_char,
+    auth_token: *const c_char
+) -> i64 {
+    if bridge.is_null() || post.is_null() || endpoint_url.is_null() || auth_token.is_null() {
+        return -1;
+    }
+
+    unsafe {
+        let bridge_ref = &mut *bridge;
+        let post_ref = &*post;
+        let endpoint_str = match CStr::from_ptr(endpoint_url).to_str() {
+            Ok(s) => s,
+            Err(_) => return -1,
+        };
+        let auth_str = match CStr::from_ptr(auth_token).to_str() {
+            Ok(s) => s,
+            Err(_) => return -1,
+        };
+
+        bridge_ref.publish_post(post_ref, endpoint_str, auth_str) as i64
+    }
+}
+
+/// Status codes `qxr_bridge_poll` writes to `out_status`.
+pub const QXR_POLL_PENDING: c_int = 0;
+pub const QXR_POLL_COMPLETED: c_int = 1;
+pub const QXR_POLL_FAILED: c_int = 2;
+
+/// Non-blocking poll for a publish started with `qxr_bridge_publish`. Writes
+/// one of `QXR_POLL_*` to `out_status`. Returns the HTTP response status code
+/// when completed, 0 for a pending or failed request, and -1 if `bridge`/
+/// `out_status` is null or `request_id` is unknown.
+#[no_mangle]
+pub extern "C" fn qxr_bridge_poll(
+    bridge: *mut QXRBridge,
+    request_id: i64,
+    out_status: *mut c_int
+) -> c_int {
+    if bridge.is_null() || out_status.is_null() {
+        return -1;
+    }
+
+    unsafe {
+        let bridge_ref = &*bridge;
+        match bridge_ref.poll_publish(request_id as u64) {
+            Some(PublishStatus::Pending) => {
+                *out_status = QXR_POLL_PENDING;
+                0
+            }
+            Some(PublishStatus::Completed { http_status }) => {
+                *out_status = QXR_POLL_COMPLETED;
+                http_status as c_int
+            }
+            Some(PublishStatus::Failed) => {
+                *out_status = QXR_POLL_FAILED;
+                0
+            }
+            None => -1,
+        }
+    }
+}
+
+/// Memory allocation helper for Python integration
+#[no_mangle]
+pub extern "C" fn qxr_bridge_alloc_string(len: usize) -> *mut c_char {
+    let layout = std::alloc::Layout::array::<u8>(len + 1).unwrap();
+    unsafe {
+        let ptr = std::alloc::alloc(layout) as *mut c_char;
+        if !ptr.is_null() {
+            *ptr.add(len) = 0; // Null terminate
+        }
+        ptr
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn qxr_bridge_free_string(ptr: *mut c_char) {
+    if !ptr.is_null() {
+        unsafe {
+            let layout = std::alloc::Layout::array::<u8>(1).unwrap();
+            std::alloc::dealloc(ptr as *mut u8, layout);
+        }
+    }
+}
+
+/// Get bridge version info
+#[no_mangle]
+pub extern "C" fn qxr_bridge_version() -> *const c_char {
+    "QXR Bridge v0.1.0\0".as_ptr() as *const c_char
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CString;
+
+    #[test]
     fn test_bridge_creation() {
         let bridge = QXRBridge::new();
         assert!(bridge.research_data.is_none());
@@ -280,4 +1511,398 @@ mod tests {
         let twitter_content = bridge.generate_social_content(&data, "twitter");
         assert!(twitter_content.len() <= 280); // Twitter character limit
     }
+
+    #[test]
+    fn test_span_aggregates_accumulate() {
+        let mut bridge = QXRBridge::new();
+
+        let strategy = CString::new("ETH Statistical Arbitrage").unwrap();
+        let timeframe = CString::new("24h").unwrap();
+
+        let data = QXRResearchData {
+            signals: 45,
+            opportunities: 8,
+            signal_strength: 1.247,
+            price_range_min: 3420.0,
+            price_range_max: 3580.0,
+            max_liquidity: 12500000,
+            strategy_len: strategy.as_bytes().len(),
+            strategy_ptr: strategy.as_ptr() as *mut c_char,
+            timeframe_len: timeframe.as_bytes().len(),
+            timeframe_ptr: timeframe.as_ptr() as *mut c_char,
+        };
+
+        bridge.process_research_data(&data);
+        bridge.process_research_data(&data);
+
+        let aggregate = bridge.span_aggregates.get("process_research_data").unwrap();
+        assert_eq!(aggregate.call_count, 2);
+        assert!(aggregate.total_nanos >= aggregate.max_nanos);
+        assert!(aggregate.min_nanos <= aggregate.max_nanos);
+    }
+
+    #[test]
+    fn test_timings_json_serialization() {
+        let mut bridge = QXRBridge::new();
+
+        let strategy = CString::new("ETH Statistical Arbitrage").unwrap();
+        let timeframe = CString::new("24h").unwrap();
+
+        let data = QXRResearchData {
+            signals: 45,
+            opportunities: 8,
+            signal_strength: 1.247,
+            price_range_min: 3420.0,
+            price_range_max: 3580.0,
+            max_liquidity: 12500000,
+            strategy_len: strategy.as_bytes().len(),
+            strategy_ptr: strategy.as_ptr() as *mut c_char,
+            timeframe_len: timeframe.as_bytes().len(),
+            timeframe_ptr: timeframe.as_ptr() as *mut c_char,
+        };
+
+        bridge.generate_social_content(&data, "linkedin");
+
+        let json = bridge.timings_json();
+        assert!(json.contains("\"process_research_data\""));
+        assert!(json.contains("\"generate_social_content\""));
+        assert!(json.contains("\"call_count\":1"));
+    }
+
+    fn sample_research_data(strategy: &CString, timeframe: &CString) -> QXRResearchData {
+        QXRResearchData {
+            signals: 45,
+            opportunities: 8,
+            signal_strength: 1.247,
+            price_range_min: 3420.0,
+            price_range_max: 3580.0,
+            max_liquidity: 12500000,
+            strategy_len: strategy.as_bytes().len(),
+            strategy_ptr: strategy.as_ptr() as *mut c_char,
+            timeframe_len: timeframe.as_bytes().len(),
+            timeframe_ptr: timeframe.as_ptr() as *mut c_char,
+        }
+    }
+
+    #[test]
+    fn test_template_field_and_precision() {
+        let mut bridge = QXRBridge::new();
+        let strategy = CString::new("ETH Statistical Arbitrage").unwrap();
+        let timeframe = CString::new("24h").unwrap();
+        let data = sample_research_data(&strategy, &timeframe);
+
+        bridge.register_template("discord", "{signals} signals @ {signal_strength:.2} strength").unwrap();
+        let rendered = bridge.render_template(&data, "discord").unwrap();
+        assert_eq!(rendered, "45 signals @ 1.25 strength");
+    }
+
+    #[test]
+    fn test_template_conditional_block() {
+        let mut bridge = QXRBridge::new();
+        let strategy = CString::new("ETH Statistical Arbitrage").unwrap();
+        let timeframe = CString::new("24h").unwrap();
+        let data = sample_research_data(&strategy, &timeframe);
+
+        bridge
+            .register_template("discord", "{opportunities} ops{if opportunities > 5} (hot){end}")
+            .unwrap();
+        let rendered = bridge.render_template(&data, "discord").unwrap();
+        assert_eq!(rendered, "8 ops (hot)");
+    }
+
+    #[test]
+    fn test_template_enforces_platform_max_len() {
+        let mut bridge = QXRBridge::new();
+        let strategy = CString::new("ETH Statistical Arbitrage").unwrap();
+        let timeframe = CString::new("24h").unwrap();
+        let data = sample_research_data(&strategy, &timeframe);
+
+        let long_template = "x".repeat(300);
+        bridge.register_template("twitter", &long_template).unwrap();
+        let result = bridge.render_template(&data, "twitter");
+        assert!(matches!(result, Err(TemplateError::ContentTooLong { .. })));
+    }
+
+    #[test]
+    fn test_template_unknown_field_and_platform() {
+        let mut bridge = QXRBridge::new();
+        let strategy = CString::new("ETH Statistical Arbitrage").unwrap();
+        let timeframe = CString::new("24h").unwrap();
+        let data = sample_research_data(&strategy, &timeframe);
+
+        bridge.register_template("discord", "{nonexistent_field}").unwrap();
+        let result = bridge.render_template(&data, "discord");
+        assert!(matches!(result, Err(TemplateError::UnknownField(_))));
+
+        let missing_platform = bridge.render_template(&data, "mastodon");
+        assert!(matches!(missing_platform, Err(TemplateError::UnknownPlatform(_))));
+    }
+
+    #[test]
+    fn test_research_data_codec_round_trip() {
+        let strategy = CString::new("ETH Statistical Arbitrage").unwrap();
+        let timeframe = CString::new("24h").unwrap();
+        let data = sample_research_data(&strategy, &timeframe);
+
+        let encoded = encode_research_data(&data).unwrap();
+        let decoded = decode_research_data(&encoded).unwrap();
+
+        assert_eq!(decoded.signals, data.signals);
+        assert_eq!(decoded.opportunities, data.opportunities);
+        assert_eq!(decoded.signal_strength, data.signal_strength);
+        assert_eq!(decoded.price_range_min, data.price_range_min);
+        assert_eq!(decoded.price_range_max, data.price_range_max);
+        assert_eq!(decoded.max_liquidity, data.max_liquidity);
+
+        let decoded_strategy = unsafe { CStr::from_ptr(decoded.strategy_ptr).to_str().unwrap() };
+        let decoded_timeframe = unsafe { CStr::from_ptr(decoded.timeframe_ptr).to_str().unwrap() };
+        assert_eq!(decoded_strategy, "ETH Statistical Arbitrage");
+        assert_eq!(decoded_timeframe, "24h");
+
+        qxr_bridge_free_string(decoded.strategy_ptr);
+        qxr_bridge_free_string(decoded.timeframe_ptr);
+    }
+
+    #[test]
+    fn test_research_data_codec_rejects_version_mismatch() {
+        let strategy = CString::new("ETH Statistical Arbitrage").unwrap();
+        let timeframe = CString::new("24h").unwrap();
+        let data = sample_research_data(&strategy, &timeframe);
+
+        let mut encoded = encode_research_data(&data).unwrap();
+        encoded[0] = RESEARCH_DATA_SCHEMA_VERSION + 1;
+
+        let result = decode_research_data(&encoded);
+        assert_eq!(result.unwrap_err(), CodecError::UnsupportedSchemaVersion(RESEARCH_DATA_SCHEMA_VERSION + 1));
+    }
+
+    /// Patch the `hashtags_count` field of an encoded (zero-hashtag) social
+    /// post with `new_count`, by re-parsing the leading version/platform/
+    /// content fields to find its exact offset rather than hardcoding one.
+    fn patch_hashtags_count(encoded: &mut [u8], new_count: i32) {
+        let mut pos = 1usize; // skip the schema version byte
+        read_str(encoded, &mut pos).unwrap(); // platform
+        read_str(encoded, &mut pos).unwrap(); // content
+        assert_eq!(encoded[pos], TAG_I32);
+        encoded[pos + 1..pos + 5].copy_from_slice(&new_count.to_le_bytes());
+    }
+
+    #[test]
+    fn test_decode_social_post_rejects_oversized_hashtags_count() {
+        let platform = CString::new("linkedin").unwrap();
+        let content = CString::new("hi").unwrap();
+        let post = QXRSocialPost {
+            platform_len: platform.as_bytes().len(),
+            platform_ptr: platform.as_ptr() as *mut c_char,
+            content_len: content.as_bytes().len(),
+            content_ptr: content.as_ptr() as *mut c_char,
+            hashtags_count: 0,
+            hashtags_ptr: std::ptr::null_mut(),
+            engagement_score: 0.0,
+        };
+        let mut encoded = encode_social_post(&post).unwrap();
+
+        // A huge attacker-controlled count that would previously reach
+        // `Vec::with_capacity` unchecked and OOM-abort the process.
+        patch_hashtags_count(&mut encoded, i32::MAX);
+
+        let result = decode_social_post(&encoded);
+        assert_eq!(result.unwrap_err(), CodecError::UnexpectedEnd);
+    }
+
+    #[test]
+    fn test_decode_social_post_rejects_negative_hashtags_count() {
+        let platform = CString::new("linkedin").unwrap();
+        let content = CString::new("hi").unwrap();
+        let post = QXRSocialPost {
+            platform_len: platform.as_bytes().len(),
+            platform_ptr: platform.as_ptr() as *mut c_char,
+            content_len: content.as_bytes().len(),
+            content_ptr: content.as_ptr() as *mut c_char,
+            hashtags_count: 0,
+            hashtags_ptr: std::ptr::null_mut(),
+            engagement_score: 0.0,
+        };
+        let mut encoded = encode_social_post(&post).unwrap();
+
+        patch_hashtags_count(&mut encoded, -1);
+
+        let result = decode_social_post(&encoded);
+        assert_eq!(result.unwrap_err(), CodecError::UnexpectedEnd);
+    }
+
+    #[test]
+    fn test_read_str_rejects_length_beyond_remaining_buffer() {
+        let mut buf = vec![TAG_STR];
+        write_varint(&mut buf, u64::MAX);
+        let mut pos = 0usize;
+        assert_eq!(read_str(&buf, &mut pos), Err(CodecError::UnexpectedEnd));
+    }
+
+    #[test]
+    fn test_social_post_codec_round_trip() {
+        let platform = CString::new("linkedin").unwrap();
+        let content = CString::new("QXR signals update").unwrap();
+        let tag1 = CString::new("#QXR").unwrap();
+        let tag2 = CString::new("#Trading").unwrap();
+        let mut hashtag_ptrs = vec![tag1.as_ptr() as *mut c_char, tag2.as_ptr() as *mut c_char];
+
+        let post = QXRSocialPost {
+            platform_len: platform.as_bytes().len(),
+            platform_ptr: platform.as_ptr() as *mut c_char,
+            content_len: content.as_bytes().len(),
+            content_ptr: content.as_ptr() as *mut c_char,
+            hashtags_count: hashtag_ptrs.len(),
+            hashtags_ptr: hashtag_ptrs.as_mut_ptr(),
+            engagement_score: 0.87,
+        };
+
+        let encoded = encode_social_post(&post).unwrap();
+        let mut decoded = decode_social_post(&encoded).unwrap();
+
+        assert_eq!(decoded.hashtags_count, 2);
+        assert_eq!(decoded.engagement_score, 0.87);
+
+        let decoded_platform = unsafe { CStr::from_ptr(decoded.platform_ptr).to_str().unwrap() };
+        let decoded_content = unsafe { CStr::from_ptr(decoded.content_ptr).to_str().unwrap() };
+        assert_eq!(decoded_platform, "linkedin");
+        assert_eq!(decoded_content, "QXR signals update");
+
+        let decoded_tags: Vec<String> = (0..decoded.hashtags_count)
+            .map(|i| unsafe { CStr::from_ptr(*decoded.hashtags_ptr.add(i)).to_str().unwrap().to_string() })
+            .collect();
+        assert_eq!(decoded_tags, vec!["#QXR".to_string(), "#Trading".to_string()]);
+
+        qxr_bridge_free_social_post(&mut decoded);
+        assert!(decoded.platform_ptr.is_null());
+        assert!(decoded.hashtags_ptr.is_null());
+    }
+
+    #[test]
+    fn test_free_social_post_is_null_safe() {
+        qxr_bridge_free_social_post(std::ptr::null_mut());
+
+        let mut empty_post = QXRSocialPost {
+            platform_len: 0,
+            platform_ptr: std::ptr::null_mut(),
+            content_len: 0,
+            content_ptr: std::ptr::null_mut(),
+            hashtags_count: 0,
+            hashtags_ptr: std::ptr::null_mut(),
+            engagement_score: 0.0,
+        };
+        qxr_bridge_free_social_post(&mut empty_post);
+    }
+
+    #[test]
+    fn test_share_and_reconstruct_single_value() {
+        let mut bridge = QXRBridge::new();
+        let strategy = CString::new("ETH Statistical Arbitrage").unwrap();
+        let timeframe = CString::new("24h").unwrap();
+        let data = sample_research_data(&strategy, &timeframe);
+
+        let score = bridge.process_research_data(&data);
+        let encoded = encode_fixedpoint(score);
+
+        let shares = share_value(encoded, 5).unwrap();
+        assert_eq!(shares.len(), 5);
+
+        let reconstructed = reconstruct(&shares);
+        assert!((reconstructed - score).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_share_value_rejects_zero_parties() {
+        assert_eq!(share_value(12345, 0), Err(ShareError::InvalidPartyCount));
+    }
+
+    #[test]
+    fn test_ffi_fixedpoint_round_trip() {
+        let encoded = qxr_encode_fixedpoint(42.5);
+        let decoded = qxr_decode_fixedpoint(encoded);
+        assert!((decoded - 42.5).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_reconstruct_aggregates_across_batch() {
+        let scores = [12.5, 48.125, -3.0625];
+        let num_parties = 4;
+
+        let mut party_sums = vec![0u128; num_parties];
+        for &score in &scores {
+            let shares = share_value(encode_fixedpoint(score), num_parties).unwrap();
+            for (party_sum, share) in party_sums.iter_mut().zip(shares.iter()) {
+                *party_sum = (*party_sum + *share as u128) % SHARE_MODULUS as u128;
+            }
+        }
+        let party_sums: Vec<u64> = party_sums.into_iter().map(|s| s as u64).collect();
+
+        let reconstructed = reconstruct(&party_sums);
+        let expected: f64 = scores.iter().sum();
+        assert!((reconstructed - expected).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_publish_and_poll_reaches_completed() {
+        use std::net::TcpListener;
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let endpoint = format!("http://{}", listener.local_addr().unwrap());
+
+        thread::spawn(move || {
+            if let Ok((mut socket, _)) = listener.accept() {
+                let mut buf = [0u8; 1024];
+                let _ = socket.read(&mut buf);
+                let _ = socket.write_all(b"HTTP/1.1 202 Accepted\r\nContent-Length: 0\r\n\r\n");
+            }
+        });
+
+        let mut bridge = QXRBridge::new();
+        let platform = CString::new("linkedin").unwrap();
+        let content = CString::new("QXR signals update").unwrap();
+        let post = QXRSocialPost {
+            platform_len: platform.as_bytes().len(),
+            platform_ptr: platform.as_ptr() as *mut c_char,
+            content_len: content.as_bytes().len(),
+            content_ptr: content.as_ptr() as *mut c_char,
+            hashtags_count: 0,
+            hashtags_ptr: std::ptr::null_mut(),
+            engagement_score: 0.0,
+        };
+
+        let request_id = bridge.publish_post(&post, &endpoint, "test-token");
+
+        let mut status = bridge.poll_publish(request_id);
+        let deadline = Instant::now() + std::time::Duration::from_secs(5);
+        while status == Some(PublishStatus::Pending) && Instant::now() < deadline {
+            thread::sleep(std::time::Duration::from_millis(10));
+            status = bridge.poll_publish(request_id);
+        }
+
+        assert_eq!(status, Some(PublishStatus::Completed { http_status: 202 }));
+    }
+
+    #[test]
+    fn test_poll_unknown_request_id_returns_none() {
+        let bridge = QXRBridge::new();
+        assert_eq!(bridge.poll_publish(9999), None);
+    }
+
+    #[test]
+    fn test_send_http_post_rejects_https_instead_of_downgrading() {
+        let result = send_http_post("https://api.example.com/posts", "secret-token", "body");
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err().kind(), std::io::ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn test_send_http_post_rejects_auth_token_with_crlf() {
+        let result = send_http_post(
+            "http://api.example.com/posts",
+            "secret-token\r\nX-Injected: evil",
+            "body",
+        );
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err().kind(), std::io::ErrorKind::InvalidInput);
+    }
 }
\ No newline at end of file